@@ -0,0 +1,71 @@
+// Battery/power telemetry, gated behind the `battery` feature so headless
+// servers (e.g. Orange Pi deployments with no battery) can opt out. Modeled
+// after bottom's `batteries` collector, which walks every battery the
+// `starship_battery` manager reports rather than assuming a single pack.
+#![cfg(feature = "battery")]
+
+use serde::{Deserialize, Serialize};
+use starship_battery::{Manager, State};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) enum BatteryState {
+    Charging,
+    Discharging,
+    Full,
+    Empty,
+    Unknown,
+}
+
+impl From<State> for BatteryState {
+    fn from(state: State) -> Self {
+        match state {
+            State::Charging => BatteryState::Charging,
+            State::Discharging => BatteryState::Discharging,
+            State::Full => BatteryState::Full,
+            State::Empty => BatteryState::Empty,
+            _ => BatteryState::Unknown,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct BatteryInfo {
+    pub(crate) charge_percent: f32,
+    pub(crate) state: BatteryState,
+    pub(crate) time_to_full_secs: Option<u64>,
+    pub(crate) time_to_empty_secs: Option<u64>,
+    pub(crate) cycle_count: Option<u32>,
+    pub(crate) energy_rate_watts: f32,
+    pub(crate) health_percent: f32,
+}
+
+pub(crate) fn get_battery_info() -> Result<Vec<BatteryInfo>, String> {
+    let manager = Manager::new().map_err(|e| e.to_string())?;
+
+    let batteries = manager
+        .batteries()
+        .map_err(|e| e.to_string())?
+        .filter_map(|battery| battery.ok())
+        .map(|battery| {
+            let energy_full = battery.energy_full().value;
+            let energy_full_design = battery.energy_full_design().value;
+            let health_percent = if energy_full_design > 0.0 {
+                (energy_full / energy_full_design) * 100.0
+            } else {
+                0.0
+            };
+
+            BatteryInfo {
+                charge_percent: battery.state_of_charge().value * 100.0,
+                state: BatteryState::from(battery.state()),
+                time_to_full_secs: battery.time_to_full().map(|t| t.value as u64),
+                time_to_empty_secs: battery.time_to_empty().map(|t| t.value as u64),
+                cycle_count: battery.cycle_count(),
+                energy_rate_watts: battery.energy_rate().value,
+                health_percent,
+            }
+        })
+        .collect();
+
+    Ok(batteries)
+}
@@ -1,4 +1,10 @@
 // CLI version for Orange Pi - no GUI dependencies
+#[cfg(feature = "battery")]
+mod battery;
+mod disk;
+mod network;
+mod power_state;
+
 use serde::{Deserialize, Serialize};
 use std::sync::Mutex;
 use sysinfo::System;
@@ -17,6 +23,22 @@ struct SystemInfo {
 
 static SYSTEM: Mutex<Option<System>> = Mutex::new(None);
 
+// Ignores the libvirt/Docker virtual interfaces (virbr0, docker0, veth*) by
+// default; any other interface, including loopback, is still printed.
+fn default_network_filter() -> network::NetworkFilter {
+    network::NetworkFilter {
+        is_list_ignored: true,
+        list: vec![
+            "virbr0".to_string(),
+            "docker0".to_string(),
+            "veth.*".to_string(),
+        ],
+        regex: true,
+        case_sensitive: false,
+        whole_word: false,
+    }
+}
+
 async fn get_system_info() -> Result<SystemInfo, String> {
     let sys_stat = SystemStat::new();
     
@@ -108,8 +130,64 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 if let Some((load1, load5, load15)) = info.load_average {
                     println!("📈 Load Average: {:.2} {:.2} {:.2}", load1, load5, load15);
                 }
-                
+
+                #[cfg(feature = "battery")]
+                match battery::get_battery_info() {
+                    Ok(batteries) => {
+                        for battery in batteries {
+                            println!(
+                                "🔋 {:.0}% ({:?}), {:.1}W, health {:.0}%",
+                                battery.charge_percent,
+                                battery.state,
+                                battery.energy_rate_watts,
+                                battery.health_percent
+                            );
+                        }
+                    }
+                    Err(e) => eprintln!("❌ Battery info error: {}", e),
+                }
+
                 println!("⏱️  Uptime: {}s", info.uptime);
+
+                for disk in disk::get_disk_info() {
+                    let removable_tag = if disk.is_removable { " [removable]" } else { "" };
+                    match (disk.used_percent, disk.used_bytes, disk.total_bytes) {
+                        (Some(used_percent), Some(used_bytes), Some(total_bytes)) => {
+                            println!(
+                                "💾 {} ({}): {:.1}% used ({} MB / {} MB){}",
+                                disk.mount_point,
+                                disk.filesystem,
+                                used_percent,
+                                used_bytes / 1024 / 1024,
+                                total_bytes / 1024 / 1024,
+                                removable_tag
+                            );
+                        }
+                        _ => {
+                            println!(
+                                "💾 {} ({}): suspended (not in D0){}",
+                                disk.mount_point, disk.filesystem, removable_tag
+                            );
+                        }
+                    }
+                }
+
+                match network::get_network_info(Some(&default_network_filter())).await {
+                    Ok(interfaces) => {
+                        for iface in interfaces {
+                            println!(
+                                "🌐 {}: ↓{} KB ↑{} KB (total ↓{} MB ↑{} MB)",
+                                iface.name,
+                                iface.bytes_received_since_last / 1024,
+                                iface.bytes_transmitted_since_last / 1024,
+                                iface.bytes_received / 1024 / 1024,
+                                iface.bytes_transmitted / 1024 / 1024
+                            );
+                        }
+                    }
+                    Err(e) => eprintln!("❌ Network info error: {}", e),
+                }
+
                 println!("===============================");
             }
             Err(e) => {
@@ -0,0 +1,67 @@
+// Disk/filesystem monitoring, shared by the Tauri app and the Orange Pi CLI
+// (each binary pulls this in via its own `mod disk;`, so a fix here applies
+// to both without copy/paste drift).
+use serde::{Deserialize, Serialize};
+
+use crate::power_state;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct DiskInfo {
+    pub(crate) mount_point: String,
+    pub(crate) filesystem: String,
+    // None when the device was skipped because it wasn't in the D0 (active)
+    // runtime power state; see `power_state`.
+    pub(crate) total_bytes: Option<u64>,
+    pub(crate) available_bytes: Option<u64>,
+    pub(crate) used_bytes: Option<u64>,
+    pub(crate) used_percent: Option<f32>,
+    pub(crate) is_removable: bool,
+}
+
+pub(crate) fn get_disk_info() -> Vec<DiskInfo> {
+    let disks = sysinfo::Disks::new_with_refreshed_list();
+
+    disks
+        .iter()
+        .map(|disk| {
+            let mount_point = disk.mount_point().to_string_lossy().to_string();
+            let filesystem = disk.file_system().to_string_lossy().to_string();
+            let is_removable = disk.is_removable();
+
+            let skip = power_state::disk_runtime_status_path(&disk.name().to_string_lossy())
+                .map(|path| !power_state::is_active(&path))
+                .unwrap_or(false);
+
+            if skip {
+                return DiskInfo {
+                    mount_point,
+                    filesystem,
+                    total_bytes: None,
+                    available_bytes: None,
+                    used_bytes: None,
+                    used_percent: None,
+                    is_removable,
+                };
+            }
+
+            let total_bytes = disk.total_space();
+            let available_bytes = disk.available_space();
+            let used_bytes = total_bytes.saturating_sub(available_bytes);
+            let used_percent = if total_bytes > 0 {
+                (used_bytes as f32 / total_bytes as f32) * 100.0
+            } else {
+                0.0
+            };
+
+            DiskInfo {
+                mount_point,
+                filesystem,
+                total_bytes: Some(total_bytes),
+                available_bytes: Some(available_bytes),
+                used_bytes: Some(used_bytes),
+                used_percent: Some(used_percent),
+                is_removable,
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,160 @@
+// Background sampler that feeds a bounded in-memory ring buffer so the
+// frontend can draw time-series charts without polling and storing samples
+// itself. Modeled after bottom's `timed_data_vec` / `DataCollection` approach.
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use sysinfo::System;
+
+const SAMPLE_INTERVAL_SECS: u64 = 2;
+const HISTORY_CAPACITY: usize = 900; // ~30 minutes at the default interval
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct TempSample {
+    pub(crate) name: String,
+    pub(crate) temperature: Option<f32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct HistorySample {
+    pub(crate) timestamp_ms: u64,
+    pub(crate) cpu_usage: f32,
+    pub(crate) per_core_usage: Vec<f32>,
+    pub(crate) memory_percent: f32,
+    pub(crate) total_memory_bytes: u64,
+    pub(crate) used_memory_bytes: u64,
+    pub(crate) swap_percent: f32,
+    pub(crate) temperatures: Vec<TempSample>,
+}
+
+static HISTORY: Mutex<VecDeque<HistorySample>> = Mutex::new(VecDeque::new());
+
+fn epoch_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+fn sample_once(sys: &mut System) {
+    sys.refresh_cpu();
+    sys.refresh_memory();
+
+    let per_core_usage: Vec<f32> = sys.cpus().iter().map(|cpu| cpu.cpu_usage()).collect();
+    let cpu_usage = if !per_core_usage.is_empty() {
+        per_core_usage.iter().sum::<f32>() / per_core_usage.len() as f32
+    } else {
+        0.0
+    };
+
+    let total_memory_bytes = sys.total_memory();
+    let used_memory_bytes = sys.used_memory();
+    let memory_percent = if total_memory_bytes > 0 {
+        (used_memory_bytes as f32 / total_memory_bytes as f32) * 100.0
+    } else {
+        0.0
+    };
+
+    let total_swap = sys.total_swap();
+    let swap_percent = if total_swap > 0 {
+        (sys.used_swap() as f32 / total_swap as f32) * 100.0
+    } else {
+        0.0
+    };
+
+    let components = sysinfo::Components::new_with_refreshed_list();
+    let temperatures = components
+        .iter()
+        .map(|component| {
+            let name = component.label().to_string();
+
+            let skip = crate::power_state::hwmon_runtime_status_path(&name)
+                .map(|path| !crate::power_state::is_active(&path))
+                .unwrap_or(false);
+            if skip {
+                return TempSample {
+                    name,
+                    temperature: None,
+                };
+            }
+
+            let temp = component.temperature();
+            TempSample {
+                name,
+                temperature: if temp.is_nan() { None } else { Some(temp) },
+            }
+        })
+        .collect();
+
+    let sample = HistorySample {
+        timestamp_ms: epoch_millis(),
+        cpu_usage,
+        per_core_usage,
+        memory_percent,
+        total_memory_bytes,
+        used_memory_bytes,
+        swap_percent,
+        temperatures,
+    };
+
+    let mut history = HISTORY.lock().unwrap();
+    if history.len() >= HISTORY_CAPACITY {
+        history.pop_front();
+    }
+    history.push_back(sample);
+}
+
+/// Starts the background sampling task. Call once from `run()`, before
+/// `tauri::Builder::run`, using Tauri's managed async runtime so it doesn't
+/// need an already-running Tokio context.
+pub(crate) fn start_sampler() {
+    tauri::async_runtime::spawn(async {
+        let mut sys = System::new_all();
+        loop {
+            sample_once(&mut sys);
+            tokio::time::sleep(Duration::from_secs(SAMPLE_INTERVAL_SECS)).await;
+        }
+    });
+}
+
+/// The most recent sample, if the sampler has run at least once.
+pub(crate) fn latest() -> Option<HistorySample> {
+    HISTORY.lock().unwrap().back().cloned()
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct HistoryResponse {
+    timestamps_ms: Vec<u64>,
+    cpu_usage: Vec<f32>,
+    per_core_usage: Vec<Vec<f32>>,
+    memory_percent: Vec<f32>,
+    swap_percent: Vec<f32>,
+    temperatures: Vec<Vec<TempSample>>,
+}
+
+/// Samples newer than `now - duration_secs`, as parallel vectors for plotting.
+pub(crate) fn get_history(duration_secs: u64) -> HistoryResponse {
+    let cutoff = epoch_millis().saturating_sub(duration_secs * 1000);
+    let history = HISTORY.lock().unwrap();
+
+    let mut response = HistoryResponse {
+        timestamps_ms: Vec::new(),
+        cpu_usage: Vec::new(),
+        per_core_usage: Vec::new(),
+        memory_percent: Vec::new(),
+        swap_percent: Vec::new(),
+        temperatures: Vec::new(),
+    };
+
+    for sample in history.iter().filter(|s| s.timestamp_ms >= cutoff) {
+        response.timestamps_ms.push(sample.timestamp_ms);
+        response.cpu_usage.push(sample.cpu_usage);
+        response.per_core_usage.push(sample.per_core_usage.clone());
+        response.memory_percent.push(sample.memory_percent);
+        response.swap_percent.push(sample.swap_percent);
+        response.temperatures.push(sample.temperatures.clone());
+    }
+
+    response
+}
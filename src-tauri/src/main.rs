@@ -1,6 +1,13 @@
 // Prevents additional console window on Windows in release, DO NOT REMOVE!!
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+#[cfg(feature = "battery")]
+mod battery;
+mod disk;
+mod history;
+mod network;
+mod power_state;
+
 use serde::{Deserialize, Serialize};
 use std::sync::Mutex;
 use sysinfo::System;
@@ -32,6 +39,32 @@ struct HardwareSpecs {
     hostname: String,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+enum SortKey {
+    Cpu,
+    Memory,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ProcessInfo {
+    pid: u32,
+    name: String,
+    command: String,
+    cpu_usage: f32,
+    memory_bytes: u64,
+    virtual_memory_bytes: u64,
+    run_time_secs: u64,
+    parent_pid: Option<u32>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct TempSensor {
+    name: String,
+    temperature: Option<f32>,
+    max: Option<f32>,
+    critical: Option<f32>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 struct ExtendedSystemInfo {
     // Basic info
@@ -56,32 +89,46 @@ static SYSTEM: Mutex<Option<System>> = Mutex::new(None);
 
 #[tauri::command]
 async fn get_system_info() -> Result<SystemInfo, String> {
+    // The background sampler (see `history`) keeps a fresh reading around, so
+    // we can report it instantly instead of blocking on a refresh+sleep. The
+    // sample carries its own memory bytes rather than us reading the global
+    // `SYSTEM`, which this fast path doesn't otherwise refresh.
+    if let Some(sample) = history::latest() {
+        return Ok(SystemInfo {
+            cpu_usage: sample.cpu_usage,
+            memory_usage: sample.memory_percent,
+            total_memory: sample.total_memory_bytes,
+            used_memory: sample.used_memory_bytes,
+            uptime: System::uptime(),
+        });
+    }
+
     let cpu_usage;
     let memory_usage;
     let total_memory;
     let used_memory;
-    
+
     {
         let mut sys_guard = SYSTEM.lock().map_err(|e| e.to_string())?;
-        
+
         if sys_guard.is_none() {
             *sys_guard = Some(System::new_all());
         }
-        
+
         if let Some(ref mut sys) = *sys_guard {
             sys.refresh_cpu();
             sys.refresh_memory();
         }
     }
-    
+
     tokio::time::sleep(tokio::time::Duration::from_millis(200)).await;
-    
+
     {
         let mut sys_guard = SYSTEM.lock().map_err(|e| e.to_string())?;
-        
+
         if let Some(ref mut sys) = *sys_guard {
             sys.refresh_cpu();
-            
+
             let cpus = sys.cpus();
             cpu_usage = if !cpus.is_empty() {
                 cpus.iter().map(|cpu| cpu.cpu_usage()).sum::<f32>() / cpus.len() as f32
@@ -100,9 +147,9 @@ async fn get_system_info() -> Result<SystemInfo, String> {
             return Err("Failed to initialize system".to_string());
         }
     }
-    
+
     let uptime = System::uptime();
-    
+
     Ok(SystemInfo {
         cpu_usage,
         memory_usage,
@@ -336,6 +383,113 @@ fn try_wmi_crate_temperature() -> Option<f32> {
     None
 }
 
+#[tauri::command]
+async fn get_disk_info() -> Result<Vec<disk::DiskInfo>, String> {
+    Ok(disk::get_disk_info())
+}
+
+#[tauri::command]
+async fn get_top_processes(limit: usize, sort_by: SortKey) -> Result<Vec<ProcessInfo>, String> {
+    let mut sys_guard = SYSTEM.lock().map_err(|e| e.to_string())?;
+
+    if sys_guard.is_none() {
+        *sys_guard = Some(System::new_all());
+    }
+
+    let sys = sys_guard.as_mut().ok_or("Failed to initialize system")?;
+
+    // sysinfo needs two refreshes with a short delay in between to compute
+    // accurate per-process CPU percentages (the first establishes a
+    // baseline). The background sampler (see `history`) refreshes CPU,
+    // memory, and temperatures on its own `System`, but never touches
+    // process info, so this command's own `SYSTEM` process table is never
+    // warmed by anything else and always needs both refreshes here.
+    sys.refresh_processes();
+    drop(sys_guard);
+    tokio::time::sleep(tokio::time::Duration::from_millis(200)).await;
+
+    let mut sys_guard = SYSTEM.lock().map_err(|e| e.to_string())?;
+    let sys = sys_guard.as_mut().ok_or("Failed to initialize system")?;
+    sys.refresh_processes();
+
+    let mut processes: Vec<ProcessInfo> = sys
+        .processes()
+        .values()
+        .map(|process| ProcessInfo {
+            pid: process.pid().as_u32(),
+            name: process.name().to_string(),
+            command: process.cmd().join(" "),
+            cpu_usage: process.cpu_usage(),
+            memory_bytes: process.memory(),
+            virtual_memory_bytes: process.virtual_memory(),
+            run_time_secs: process.run_time(),
+            parent_pid: process.parent().map(|pid| pid.as_u32()),
+        })
+        .collect();
+
+    match sort_by {
+        SortKey::Cpu => processes.sort_by(|a, b| b.cpu_usage.total_cmp(&a.cpu_usage)),
+        SortKey::Memory => processes.sort_by(|a, b| b.memory_bytes.cmp(&a.memory_bytes)),
+    }
+
+    processes.truncate(limit);
+
+    Ok(processes)
+}
+
+#[cfg(feature = "battery")]
+#[tauri::command]
+async fn get_battery_info() -> Result<Vec<battery::BatteryInfo>, String> {
+    battery::get_battery_info()
+}
+
+#[tauri::command]
+async fn get_history(duration_secs: u64) -> Result<history::HistoryResponse, String> {
+    Ok(history::get_history(duration_secs))
+}
+
+#[tauri::command]
+async fn get_temperature_sensors() -> Result<Vec<TempSensor>, String> {
+    let components = sysinfo::Components::new_with_refreshed_list();
+
+    let sensors = components
+        .iter()
+        .map(|component| {
+            let label = component.label().to_string();
+
+            if let Some(power_path) = power_state::hwmon_runtime_status_path(&label) {
+                if !power_state::is_active(&power_path) {
+                    return TempSensor {
+                        name: label,
+                        temperature: None,
+                        max: None,
+                        critical: None,
+                    };
+                }
+            }
+
+            let temperature = component.temperature();
+            let max = component.max();
+
+            TempSensor {
+                name: label,
+                temperature: if temperature.is_nan() { None } else { Some(temperature) },
+                max: if max.is_nan() { None } else { Some(max) },
+                critical: component.critical(),
+            }
+        })
+        .collect();
+
+    Ok(sensors)
+}
+
+#[tauri::command]
+async fn get_network_info(
+    filter: Option<network::NetworkFilter>,
+) -> Result<Vec<network::NetworkInfo>, String> {
+    network::get_network_info(filter.as_ref()).await
+}
+
 #[tauri::command]
 fn test_command() -> String {
     "Tauri is working!".to_string()
@@ -426,13 +580,22 @@ async fn get_extended_system_info() -> Result<ExtendedSystemInfo, String> {
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
+    history::start_sampler();
+
     tauri::Builder::default()
         .plugin(tauri_plugin_shell::init())
         .invoke_handler(tauri::generate_handler![
-            get_system_info, 
-            get_cpu_temperature, 
+            get_system_info,
+            get_cpu_temperature,
             get_extended_system_info,
-            get_hardware_specs, 
+            get_hardware_specs,
+            get_disk_info,
+            get_network_info,
+            get_temperature_sensors,
+            get_history,
+            get_top_processes,
+            #[cfg(feature = "battery")]
+            get_battery_info,
             test_command
         ])
         .run(tauri::generate_context!())
@@ -0,0 +1,174 @@
+// Network interface monitoring, shared by the Tauri app and the Orange Pi
+// CLI (each binary pulls this in via its own `mod network;`, so a fix here
+// applies to both without copy/paste drift).
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
+use std::time::Duration;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct NetworkInfo {
+    pub(crate) name: String,
+    pub(crate) bytes_received: u64,
+    pub(crate) bytes_transmitted: u64,
+    pub(crate) bytes_received_since_last: u64,
+    pub(crate) bytes_transmitted_since_last: u64,
+    pub(crate) packets_received: u64,
+    pub(crate) packets_transmitted: u64,
+    pub(crate) errors_received: u64,
+    pub(crate) errors_transmitted: u64,
+}
+
+// Modeled after bottom's net_filter: keeps or drops an interface by matching
+// its name against `list`, either literally or as a regex.
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct NetworkFilter {
+    pub(crate) is_list_ignored: bool,
+    pub(crate) list: Vec<String>,
+    pub(crate) regex: bool,
+    pub(crate) case_sensitive: bool,
+    pub(crate) whole_word: bool,
+}
+
+impl NetworkFilter {
+    fn compile(&self) -> Result<Vec<Regex>, String> {
+        self.list
+            .iter()
+            .map(|pattern| {
+                let pattern = if self.regex {
+                    pattern.clone()
+                } else {
+                    regex::escape(pattern)
+                };
+                let pattern = if self.whole_word {
+                    format!("^{}$", pattern)
+                } else {
+                    pattern
+                };
+
+                regex::RegexBuilder::new(&pattern)
+                    .case_insensitive(!self.case_sensitive)
+                    .build()
+                    .map_err(|e| e.to_string())
+            })
+            .collect()
+    }
+
+    fn keeps(&self, name: &str, compiled: &[Regex]) -> bool {
+        let is_match = compiled.iter().any(|re| re.is_match(name));
+        is_match != self.is_list_ignored
+    }
+}
+
+// A single persistent `Networks` instance. `received()`/`transmitted()` are
+// deltas since the *previous* refresh, so a fresh
+// `Networks::new_with_refreshed_list()` on every call has nothing to diff
+// against and always reports ~0; keeping one instance around and calling
+// `refresh()` on it is what makes those deltas real.
+static NETWORKS: Mutex<Option<sysinfo::Networks>> = Mutex::new(None);
+
+pub(crate) async fn get_network_info(
+    filter: Option<&NetworkFilter>,
+) -> Result<Vec<NetworkInfo>, String> {
+    let compiled_filter = filter
+        .map(|f| f.compile().map(|compiled| (f, compiled)))
+        .transpose()?;
+
+    let just_initialized = {
+        let mut guard = NETWORKS.lock().map_err(|e| e.to_string())?;
+        if guard.is_none() {
+            *guard = Some(sysinfo::Networks::new_with_refreshed_list());
+            true
+        } else {
+            false
+        }
+    };
+
+    // On the very first call there's no prior refresh to diff against yet,
+    // so take one right away (mirrors the CPU-usage refresh+sleep pattern).
+    if just_initialized {
+        tokio::time::sleep(Duration::from_millis(200)).await;
+    }
+
+    let mut guard = NETWORKS.lock().map_err(|e| e.to_string())?;
+    let networks = guard.as_mut().ok_or("Failed to initialize networks")?;
+    networks.refresh();
+
+    let network_info = networks
+        .iter()
+        .filter(|(name, _)| match &compiled_filter {
+            Some((filter, compiled)) => filter.keeps(name, compiled),
+            None => true,
+        })
+        .map(|(name, data)| NetworkInfo {
+            name: name.clone(),
+            bytes_received: data.total_received(),
+            bytes_transmitted: data.total_transmitted(),
+            bytes_received_since_last: data.received(),
+            bytes_transmitted_since_last: data.transmitted(),
+            packets_received: data.total_packets_received(),
+            packets_transmitted: data.total_packets_transmitted(),
+            errors_received: data.total_errors_on_received(),
+            errors_transmitted: data.total_errors_on_transmitted(),
+        })
+        .collect();
+
+    Ok(network_info)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filter(is_list_ignored: bool, list: &[&str], regex: bool, whole_word: bool) -> NetworkFilter {
+        NetworkFilter {
+            is_list_ignored,
+            list: list.iter().map(|s| s.to_string()).collect(),
+            regex,
+            case_sensitive: false,
+            whole_word,
+        }
+    }
+
+    #[test]
+    fn literal_list_keeps_only_unlisted_interfaces_when_ignored() {
+        let f = filter(true, &["docker0"], false, false);
+        let compiled = f.compile().unwrap();
+        assert!(!f.keeps("docker0", &compiled));
+        assert!(f.keeps("eth0", &compiled));
+    }
+
+    #[test]
+    fn literal_list_keeps_only_listed_interfaces_when_not_ignored() {
+        let f = filter(false, &["eth0"], false, false);
+        let compiled = f.compile().unwrap();
+        assert!(f.keeps("eth0", &compiled));
+        assert!(!f.keeps("docker0", &compiled));
+    }
+
+    #[test]
+    fn regex_list_matches_as_pattern_not_literal() {
+        let f = filter(true, &["veth.*"], true, false);
+        let compiled = f.compile().unwrap();
+        assert!(!f.keeps("veth1234", &compiled));
+        assert!(f.keeps("eth0", &compiled));
+    }
+
+    #[test]
+    fn non_regex_list_escapes_special_characters() {
+        // `.` would match any character as a regex, but `regex: false` means
+        // it should only match the literal interface name "br.0".
+        let f = filter(true, &["br.0"], false, false);
+        let compiled = f.compile().unwrap();
+        assert!(!f.keeps("br.0", &compiled));
+        assert!(f.keeps("brX0", &compiled));
+    }
+
+    #[test]
+    fn whole_word_requires_full_match() {
+        let f = filter(true, &["eth"], true, true);
+        let compiled = f.compile().unwrap();
+        assert!(f.keeps("eth0", &compiled));
+        assert!(!f.keeps("eth", &compiled));
+    }
+}
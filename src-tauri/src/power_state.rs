@@ -0,0 +1,148 @@
+// Repeatedly reading sysfs thermal/disk attributes can wake a suspended
+// device out of a low-power runtime PM state just to service the read. On
+// Linux, `power/runtime_status` under a device's sysfs node reports whether
+// it is currently "active" (D0) without touching the device itself, so we
+// check it first and skip the real read when the device is elsewhere.
+// Non-Linux platforms don't expose this, so we report everything as active.
+use std::path::{Path, PathBuf};
+
+#[cfg(target_os = "linux")]
+pub(crate) fn is_active(power_status_path: &Path) -> bool {
+    match std::fs::read_to_string(power_status_path) {
+        Ok(status) => status.trim() == "active",
+        // No runtime PM status exposed (virtual device, older kernel, ...):
+        // assume active rather than silently dropping the reading.
+        Err(_) => true,
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn is_active(_power_status_path: &Path) -> bool {
+    true
+}
+
+// `sysinfo::Disk::name()` is a device path such as `/dev/sda1`, and a
+// partition's sysfs node has no `device` symlink of its own (that lives on
+// its parent whole-disk node, e.g. `/sys/class/block/sda`). Strip the `/dev/`
+// prefix, fall back to the parent device for a partition name, and only
+// return a path once we've confirmed it actually exists.
+#[cfg(target_os = "linux")]
+pub(crate) fn disk_runtime_status_path(device_name: &str) -> Option<PathBuf> {
+    let name = device_name.strip_prefix("/dev/").unwrap_or(device_name);
+
+    let mut candidates = vec![name.to_string()];
+    if let Some(parent) = strip_partition_suffix(name) {
+        candidates.push(parent);
+    }
+
+    candidates.into_iter().find_map(|candidate| {
+        let path = PathBuf::from(format!(
+            "/sys/class/block/{}/device/power/runtime_status",
+            candidate
+        ));
+        path.exists().then_some(path)
+    })
+}
+
+#[cfg(target_os = "linux")]
+fn strip_partition_suffix(name: &str) -> Option<String> {
+    // nvme0n1p1 / mmcblk0p1 style: the device name itself ends in a digit,
+    // so the partition suffix is only the digits after a `p`.
+    if let Some(idx) = name.rfind('p') {
+        let (prefix, digits) = (&name[..idx], &name[idx + 1..]);
+        let digits_only = !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit());
+        let prefix_ends_in_digit = prefix.chars().last().is_some_and(|c| c.is_ascii_digit());
+        if digits_only && prefix_ends_in_digit {
+            return Some(prefix.to_string());
+        }
+    }
+
+    // sda1 / hda2 style: a whole disk name like `sda` never ends in a digit,
+    // so any trailing digit there is a genuine partition number. This branch
+    // must not run for nvme*/mmcblk* names: their whole-disk form already
+    // ends in a digit (`nvme0n1`, `mmcblk0`), so blindly trimming digits
+    // would turn a whole disk into a bogus, shorter "parent".
+    if !name.starts_with("nvme") && !name.starts_with("mmcblk") {
+        let trimmed = name.trim_end_matches(|c: char| c.is_ascii_digit());
+        if trimmed != name && !trimmed.is_empty() {
+            return Some(trimmed.to_string());
+        }
+    }
+
+    None
+}
+
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn disk_runtime_status_path(_device_name: &str) -> Option<PathBuf> {
+    None
+}
+
+// Matches a sensor label against each hwmon chip's `name` file on a whole
+// token (not substring) basis, and only returns a path for chips that
+// actually expose a `device/power/runtime_status` node — plenty (coretemp,
+// acpitz, ...) don't, and we want the guard to no-op for those rather than
+// pretend it gated them.
+#[cfg(target_os = "linux")]
+pub(crate) fn hwmon_runtime_status_path(sensor_label: &str) -> Option<PathBuf> {
+    let label = sensor_label.to_lowercase();
+    let entries = std::fs::read_dir("/sys/class/hwmon").ok()?;
+
+    for entry in entries.flatten() {
+        let Ok(chip_name) = std::fs::read_to_string(entry.path().join("name")) else {
+            continue;
+        };
+        let chip_name = chip_name.trim().to_lowercase();
+        if chip_name.is_empty() {
+            continue;
+        }
+
+        let is_match = label
+            .split(|c: char| !c.is_alphanumeric())
+            .any(|token| token == chip_name);
+        if !is_match {
+            continue;
+        }
+
+        let power_path = entry.path().join("device/power/runtime_status");
+        if power_path.exists() {
+            return Some(power_path);
+        }
+    }
+
+    None
+}
+
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn hwmon_runtime_status_path(_sensor_label: &str) -> Option<PathBuf> {
+    None
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strips_sd_style_partition_suffix() {
+        assert_eq!(strip_partition_suffix("sda1"), Some("sda".to_string()));
+        assert_eq!(strip_partition_suffix("hda2"), Some("hda".to_string()));
+    }
+
+    #[test]
+    fn strips_nvme_and_mmcblk_partition_suffix() {
+        assert_eq!(
+            strip_partition_suffix("nvme0n1p2"),
+            Some("nvme0n1".to_string())
+        );
+        assert_eq!(
+            strip_partition_suffix("mmcblk0p1"),
+            Some("mmcblk0".to_string())
+        );
+    }
+
+    #[test]
+    fn leaves_whole_disk_names_alone() {
+        assert_eq!(strip_partition_suffix("sda"), None);
+        assert_eq!(strip_partition_suffix("nvme0n1"), None);
+        assert_eq!(strip_partition_suffix("mmcblk0"), None);
+    }
+}